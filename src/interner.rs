@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use simdutf8::basic::from_utf8;
+
+use crate::{DictionaryArrayResult, OtlpProcessingError, ProcessingMode};
+
+/// Incremental dictionary builder for streaming input: unlike
+/// `process_otlp_strings_dict`, which needs the whole `Vec<Vec<u8>>` up
+/// front, a `StringInterner` can be driven one field at a time from a
+/// decoder loop (e.g. reading OTLP data off a socket) without buffering the
+/// input slices: it keeps only each unique string once plus one `u32` key per
+/// pushed slice, so its memory still grows with the number of pushes.
+#[derive(Debug)]
+pub struct StringInterner {
+    dictionary: HashMap<Vec<u8>, usize>,
+    values: Vec<String>,
+    keys: Vec<u32>,
+    mode: ProcessingMode,
+}
+
+impl Default for StringInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StringInterner {
+    /// Creates an empty interner in `ProcessingMode::Strict`.
+    pub fn new() -> Self {
+        Self::with_mode(ProcessingMode::Strict)
+    }
+
+    /// Creates an empty interner with the given `ProcessingMode`.
+    pub fn with_mode(mode: ProcessingMode) -> Self {
+        Self {
+            dictionary: HashMap::new(),
+            values: Vec::new(),
+            keys: Vec::new(),
+            mode,
+        }
+    }
+
+    /// Interns one more byte slice: in `Strict` mode, validates it with
+    /// simdutf8 only on first sight of a new slice, failing with a
+    /// `Utf8Error` that pinpoints this position on invalid UTF-8; in `Lossy`
+    /// mode, a new slice is converted with `String::from_utf8_lossy` instead
+    /// of validated, so `push` never fails. Either way it appends the
+    /// assigned index to the key stream and returns it immediately.
+    pub fn push(&mut self, bytes: &[u8]) -> Result<u32, OtlpProcessingError> {
+        let index = if let Some(&index) = self.dictionary.get(bytes) {
+            index
+        } else {
+            let s = match self.mode {
+                ProcessingMode::Strict => {
+                    if let Err(source) = from_utf8(bytes) {
+                        return Err(OtlpProcessingError::Utf8Error {
+                            dict_index: self.values.len(),
+                            first_input_index: self.keys.len(),
+                            source,
+                        });
+                    }
+                    unsafe { String::from_utf8_unchecked(bytes.to_vec()) }
+                }
+                ProcessingMode::Lossy => String::from_utf8_lossy(bytes).into_owned(),
+            };
+            let index = self.values.len();
+            self.dictionary.insert(bytes.to_vec(), index);
+            self.values.push(s);
+            index
+        };
+
+        let index = index as u32;
+        self.keys.push(index);
+        Ok(index)
+    }
+
+    /// Consumes the interner, returning the dictionary-encoded result built
+    /// up so far.
+    pub fn finish(self) -> DictionaryArrayResult {
+        DictionaryArrayResult {
+            values: self.values,
+            keys: self.keys,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_interner_push_and_finish() {
+        let mut interner = StringInterner::new();
+
+        assert_eq!(interner.push(b"service.name").unwrap(), 0);
+        assert_eq!(interner.push(b"status").unwrap(), 1);
+        assert_eq!(interner.push(b"service.name").unwrap(), 0);
+        assert_eq!(interner.push(b"region").unwrap(), 2);
+
+        let result = interner.finish();
+
+        assert_eq!(
+            result.values,
+            vec!["service.name".to_string(), "status".to_string(), "region".to_string()]
+        );
+        assert_eq!(result.keys, vec![0, 1, 0, 2]);
+    }
+
+    #[test]
+    fn test_string_interner_invalid_utf8() {
+        let mut interner = StringInterner::new();
+
+        assert!(interner.push(b"hello").is_ok());
+        assert!(interner.push(&[0xFF, 0xF0, 0x9F]).is_err());
+    }
+
+    #[test]
+    fn test_string_interner_lossy_mode() {
+        let mut interner = StringInterner::with_mode(ProcessingMode::Lossy);
+
+        assert!(interner.push(b"hello").is_ok());
+        assert!(interner.push(&[0xFF, 0xF0, 0x9F]).is_ok());
+
+        let result = interner.finish();
+        assert!(result.values.iter().any(|s| s.contains('\u{FFFD}')));
+    }
+}
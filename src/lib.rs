@@ -1,43 +1,103 @@
 use std::collections::HashMap;
 use simdutf8::basic::from_utf8; // For accelerated validation
 
+mod otlp_decode;
+pub use otlp_decode::{decode_export_logs_service_request, DecodedOtlpLogs, OtlpDecodeError, StringRole};
+
+mod parallel;
+pub use parallel::{process_otlp_strings_parallel, process_otlp_strings_parallel_with_mode};
+
+mod interner;
+pub use interner::StringInterner;
+
 /// Represents an error in validation or other processing steps.
 #[derive(Debug)]
 pub enum OtlpProcessingError {
-    Utf8Error(simdutf8::basic::Utf8Error),
+    /// Invalid UTF-8 was found in a dictionary entry. `dict_index` is the
+    /// entry's index into the dictionary's `values`, and `first_input_index`
+    /// is the position in the original input where that entry was first
+    /// seen, so callers can pinpoint the offending input without re-scanning.
+    Utf8Error {
+        dict_index: usize,
+        first_input_index: usize,
+        source: simdutf8::basic::Utf8Error,
+    },
     // Add more variants if needed
 }
 
-impl From<simdutf8::basic::Utf8Error> for OtlpProcessingError {
-    fn from(err: simdutf8::basic::Utf8Error) -> Self {
-        OtlpProcessingError::Utf8Error(err)
-    }
+/// Controls how invalid UTF-8 in a dictionary entry is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessingMode {
+    /// Fail the whole call with a `Utf8Error` that pinpoints the offending
+    /// dictionary entry and input position.
+    Strict,
+    /// Don't reject invalid entries: convert them via
+    /// `String::from_utf8_lossy`, replacing ill-formed sequences with
+    /// U+FFFD, and let the call succeed.
+    Lossy,
 }
 
-/// Processes a collection of raw byte slices (like OTLP-encoded strings).
+/// Dictionary-encoded processing result: each unique validated string is stored
+/// exactly once in `values`, and `keys[i]` is the index into `values` for input
+/// position `i`.
+///
+/// This is the same physical layout as an Arrow `DictionaryArray` (an
+/// `Int32`/`UInt32` keys buffer plus a `Utf8` values buffer), so a consumer
+/// building columnar batches (e.g. an OTLP ingest pipeline) can hand these
+/// buffers straight to Arrow instead of paying for an extra materialization
+/// pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictionaryArrayResult {
+    pub values: Vec<String>,
+    pub keys: Vec<u32>,
+}
+
+/// Processes a collection of raw byte slices (like OTLP-encoded strings) into
+/// a dictionary-encoded result, in `ProcessingMode::Strict` mode.
 /// 1. Collects and deduplicates byte slices (dictionary).
 /// 2. Validates the unique entries using simdutf8 (once per unique string).
 /// 3. Converts validated bytes to UTF-8 Strings without redundant copying.
-pub fn process_otlp_strings(
+///
+/// This is a convenience wrapper around
+/// [`process_otlp_strings_dict_with_mode`] for callers that want strict
+/// failure on invalid UTF-8.
+pub fn process_otlp_strings_dict(
     raw_strings: Vec<Vec<u8>>,
-) -> Result<Vec<String>, OtlpProcessingError> {
+) -> Result<DictionaryArrayResult, OtlpProcessingError> {
+    process_otlp_strings_dict_with_mode(raw_strings, ProcessingMode::Strict)
+}
+
+/// Processes a collection of raw byte slices (like OTLP-encoded strings) into
+/// a dictionary-encoded result.
+/// 1. Collects and deduplicates byte slices (dictionary).
+/// 2. Validates the unique entries using simdutf8 (once per unique string).
+/// 3. Converts validated bytes to UTF-8 Strings without redundant copying.
+///
+/// In `ProcessingMode::Strict`, an invalid entry fails the whole call with a
+/// `Utf8Error` that carries its dictionary index and the input position it
+/// was first seen at. In `ProcessingMode::Lossy`, invalid entries are
+/// converted with `String::from_utf8_lossy` instead of rejected.
+pub fn process_otlp_strings_dict_with_mode(
+    raw_strings: Vec<Vec<u8>>,
+    mode: ProcessingMode,
+) -> Result<DictionaryArrayResult, OtlpProcessingError> {
     // Step 1: Build a dictionary to store each unique string once.
     //
-    // We map each unique byte vector -> integer index.
+    // We map each unique byte vector -> (integer index, first input position).
     // The 'dictionary_array' holds the references (indexes) for each item in the
     // original input order.
 
-    let mut dictionary: HashMap<Vec<u8>, usize> = HashMap::new();
+    let mut dictionary: HashMap<Vec<u8>, (usize, usize)> = HashMap::new();
     let mut dictionary_array = Vec::with_capacity(raw_strings.len());
 
-    for raw in raw_strings {
-        if let Some(&existing_index) = dictionary.get(&raw) {
+    for (input_index, raw) in raw_strings.into_iter().enumerate() {
+        if let Some(&(existing_index, _)) = dictionary.get(&raw) {
             // Already in dictionary
             dictionary_array.push(existing_index);
         } else {
             let new_index = dictionary.len();
             dictionary_array.push(new_index);
-            dictionary.insert(raw, new_index);
+            dictionary.insert(raw, (new_index, input_index));
         }
     }
 
@@ -46,39 +106,71 @@ pub fn process_otlp_strings(
     // We iterate over the dictionary keys (unique byte slices) and confirm
     // they are valid UTF-8 data using simdutf8. This ensures each unique
     // byte slice is validated exactly once.
+    //
+    // Only Strict mode needs this check, since it's the only thing that can
+    // make the call bail early. Lossy mode skips it: `from_utf8_lossy` in
+    // Step 3 is the only UTF-8 pass it needs, so entries still get validated
+    // exactly once either way.
 
-    for key in dictionary.keys() {
-        // If any entry is not valid UTF-8, this returns an error right away.
-        from_utf8(key)?;
+    if mode == ProcessingMode::Strict {
+        for (key, &(dict_index, first_input_index)) in &dictionary {
+            if let Err(source) = from_utf8(key) {
+                return Err(OtlpProcessingError::Utf8Error {
+                    dict_index,
+                    first_input_index,
+                    source,
+                });
+            }
+        }
     }
 
     // Step 3: Convert the validated dictionary byte slices to final Strings.
     //
-    // Because they've already been validated, we can safely use
-    // `String::from_utf8_unchecked` to avoid a second pass of UTF-8 checks.
-    // Note that this does allocate new Strings in memory. If the goal is truly
-    // zero-copy, you'd need a more specialized data structure (e.g. Arrow arrays).
-    //
-    // For demonstration, we show a minimal-cost conversion:
-    // - produce one String per unique entry
+    // Because they've already been validated (or we're in Lossy mode, where
+    // `from_utf8_lossy` replaces ill-formed sequences with U+FFFD), we avoid
+    // a second strict pass of UTF-8 checks.
 
     let mut unique_strings = vec![String::new(); dictionary.len()];
 
-    // dictionary is <Vec<u8>, usize>. We invert it here into the final Strings.
-    for (key_bytes, index) in dictionary {
-        let s = unsafe { String::from_utf8_unchecked(key_bytes) };
+    // dictionary is <Vec<u8>, (usize, usize)>. We invert it here into the final Strings.
+    for (key_bytes, (index, _first_input_index)) in dictionary {
+        let s = match mode {
+            ProcessingMode::Strict => unsafe { String::from_utf8_unchecked(key_bytes) },
+            ProcessingMode::Lossy => String::from_utf8_lossy(&key_bytes).into_owned(),
+        };
         unique_strings[index] = s;
     }
 
-    // Step 4: Reconstruct full result (in the original order) using dictionary_array.
-    // Each entry in dictionary_array references the unique validated String in unique_strings.
+    // Step 4: `dictionary_array` already holds the keys buffer (original order,
+    // indexing into `unique_strings`) -- just narrow it to the Arrow-style u32
+    // key width instead of materializing a cloned String per input position.
 
-    let result: Vec<String> = dictionary_array
-        .into_iter()
-        .map(|idx| unique_strings[idx].clone())
-        .collect();
+    let keys: Vec<u32> = dictionary_array.into_iter().map(|idx| idx as u32).collect();
 
-    Ok(result)
+    Ok(DictionaryArrayResult {
+        values: unique_strings,
+        keys,
+    })
+}
+
+/// Processes a collection of raw byte slices (like OTLP-encoded strings).
+/// 1. Collects and deduplicates byte slices (dictionary).
+/// 2. Validates the unique entries using simdutf8 (once per unique string).
+/// 3. Converts validated bytes to UTF-8 Strings without redundant copying.
+///
+/// This is a convenience wrapper around [`process_otlp_strings_dict`] for
+/// callers that don't care about the dictionary encoding and just want the
+/// original order materialized back out. If the goal is truly zero-copy
+/// output (e.g. handing buffers to Arrow), call `process_otlp_strings_dict`
+/// directly instead.
+pub fn process_otlp_strings(
+    raw_strings: Vec<Vec<u8>>,
+) -> Result<Vec<String>, OtlpProcessingError> {
+    let DictionaryArrayResult { values, keys } = process_otlp_strings_dict(raw_strings)?;
+    Ok(keys
+        .into_iter()
+        .map(|k| values[k as usize].clone())
+        .collect())
 }
 
 /// Debug version of process_otlp_strings that prints detailed information about each step
@@ -96,47 +188,52 @@ pub fn process_otlp_strings_debug(
         );
     }
     
-    let mut dictionary: HashMap<Vec<u8>, usize> = HashMap::new();
+    let mut dictionary: HashMap<Vec<u8>, (usize, usize)> = HashMap::new();
     let mut dictionary_array = Vec::with_capacity(raw_strings.len());
 
     for (input_idx, raw) in raw_strings.iter().enumerate() {
-        if let Some(&existing_index) = dictionary.get(raw) {
-            println!("  Found duplicate: Input[{}] -> Dictionary[{}] ('{}')", 
+        if let Some(&(existing_index, _)) = dictionary.get(raw) {
+            println!("  Found duplicate: Input[{}] -> Dictionary[{}] ('{}')",
                 input_idx, existing_index, String::from_utf8_lossy(raw));
             dictionary_array.push(existing_index);
         } else {
             let new_index = dictionary.len();
-            println!("  New entry: Input[{}] -> Dictionary[{}] ('{}')", 
+            println!("  New entry: Input[{}] -> Dictionary[{}] ('{}')",
                 input_idx, new_index, String::from_utf8_lossy(raw));
             dictionary_array.push(new_index);
-            dictionary.insert(raw.clone(), new_index);
+            dictionary.insert(raw.clone(), (new_index, input_idx));
         }
     }
-    
+
     println!("\nDictionary contents ({} unique entries):", dictionary.len());
     let mut dict_entries: Vec<_> = dictionary.iter().collect();
-    dict_entries.sort_by_key(|(_, &index)| index);
-    for (bytes, &index) in dict_entries {
-        println!("  Dictionary[{}]: {:?} -> '{}'", 
+    dict_entries.sort_by_key(|(_, &(index, _))| index);
+    for (bytes, &(index, _)) in dict_entries {
+        println!("  Dictionary[{}]: {:?} -> '{}'",
             index, bytes, String::from_utf8_lossy(bytes));
     }
-    
+
     println!("\nDictionary array (original order mapping): {:?}", dictionary_array);
 
     println!("\nStep 2: Validating {} unique dictionary entries using simdutf8", dictionary.len());
-    for (i, key) in dictionary.keys().enumerate() {
+    for (i, (key, &(dict_index, first_input_index))) in dictionary.iter().enumerate() {
         match from_utf8(key) {
             Ok(valid_str) => println!("  ✓ Dictionary entry {}: '{}' is valid UTF-8", i, valid_str),
-            Err(e) => {
-                println!("  ✗ Dictionary entry {}: {:?} is invalid UTF-8: {:?}", i, key, e);
-                return Err(e.into());
+            Err(source) => {
+                println!("  ✗ Dictionary entry {}: {:?} is invalid UTF-8 (first seen at Input[{}]): {:?}",
+                    i, key, first_input_index, source);
+                return Err(OtlpProcessingError::Utf8Error {
+                    dict_index,
+                    first_input_index,
+                    source,
+                });
             }
         }
     }
 
     println!("\nStep 3: Converting validated dictionary entries to Strings");
     let mut unique_strings = vec![String::new(); dictionary.len()];
-    for (key_bytes, index) in dictionary {
+    for (key_bytes, (index, _first_input_index)) in dictionary {
         let s = unsafe { String::from_utf8_unchecked(key_bytes) };
         println!("  Dictionary[{}]: Converted to String '{}'", index, s);
         unique_strings[index] = s;
@@ -185,6 +282,27 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_process_otlp_strings_dict() {
+        // Same fixture as test_otlp_string_processing, but checked against the
+        // dictionary-encoded result directly.
+        let raw_data = vec![
+            b"service.name".to_vec(),
+            b"status".to_vec(),
+            b"service.name".to_vec(),
+            b"region".to_vec(),
+        ];
+
+        let result = process_otlp_strings_dict(raw_data).expect("UTF-8 validation failed");
+
+        assert_eq!(result.values, vec![
+            "service.name".to_string(),
+            "status".to_string(),
+            "region".to_string(),
+        ]);
+        assert_eq!(result.keys, vec![0, 1, 0, 2]);
+    }
+
     #[test]
     fn test_invalid_data() {
         // Contains invalid UTF-8: 0xFF is not valid in UTF-8
@@ -194,6 +312,36 @@ mod tests {
         assert!(result.is_err(), "Should fail on invalid UTF-8 data");
     }
 
+    #[test]
+    fn test_strict_error_pinpoints_offending_input() {
+        let invalid = vec![
+            b"hello".to_vec(),
+            b"world".to_vec(),
+            vec![0xFF, 0xF0, 0x9F],
+        ];
+
+        let err = process_otlp_strings_dict(invalid).expect_err("should fail on invalid UTF-8");
+        let OtlpProcessingError::Utf8Error {
+            dict_index,
+            first_input_index,
+            ..
+        } = err;
+
+        assert_eq!(dict_index, 2);
+        assert_eq!(first_input_index, 2);
+    }
+
+    #[test]
+    fn test_lossy_mode_replaces_invalid_utf8_instead_of_failing() {
+        let invalid = vec![b"hello".to_vec(), vec![0xFF, 0xF0, 0x9F]];
+
+        let result = process_otlp_strings_dict_with_mode(invalid, ProcessingMode::Lossy)
+            .expect("lossy mode should not fail on invalid UTF-8");
+
+        assert!(result.values.contains(&"hello".to_string()));
+        assert!(result.values.iter().any(|s| s.contains('\u{FFFD}')));
+    }
+
     #[test]
     fn test_debug_processing() {
         println!("\n🔍 Running debug test to show internal processing steps...");
@@ -0,0 +1,283 @@
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+use opentelemetry_proto::tonic::common::v1::any_value::Value;
+use opentelemetry_proto::tonic::common::v1::KeyValue;
+use prost::Message;
+
+use crate::{process_otlp_strings_dict, DictionaryArrayResult, OtlpProcessingError};
+
+/// The semantic role a decoded OTLP string field played, so a consumer can
+/// reassemble columnar logs from the flat dictionary-encoded output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringRole {
+    ResourceAttributeKey,
+    ResourceAttributeValue,
+    ScopeName,
+    ScopeVersion,
+    ScopeAttributeKey,
+    ScopeAttributeValue,
+    SeverityText,
+    EventName,
+    LogAttributeKey,
+    LogAttributeValue,
+    SchemaUrl,
+}
+
+/// Error decoding a serialized `ExportLogsServiceRequest` into dictionary-encoded
+/// strings.
+#[derive(Debug)]
+pub enum OtlpDecodeError {
+    Decode(prost::DecodeError),
+    Processing(OtlpProcessingError),
+}
+
+impl From<prost::DecodeError> for OtlpDecodeError {
+    fn from(err: prost::DecodeError) -> Self {
+        OtlpDecodeError::Decode(err)
+    }
+}
+
+impl From<OtlpProcessingError> for OtlpDecodeError {
+    fn from(err: OtlpProcessingError) -> Self {
+        OtlpDecodeError::Processing(err)
+    }
+}
+
+/// Dictionary-encoded strings decoded from an OTLP logs export request, with a
+/// parallel `roles` vec (same length and order as `dictionary.keys`) tagging
+/// the semantic role of each decoded field.
+pub struct DecodedOtlpLogs {
+    pub dictionary: DictionaryArrayResult,
+    pub roles: Vec<StringRole>,
+}
+
+/// Appends `key`/string-`value` pairs from a slice of OTLP `KeyValue`
+/// attributes to `raw_strings`/`roles`. Non-string attribute values are not
+/// string-bearing and are skipped.
+fn collect_attributes(
+    attributes: &[KeyValue],
+    key_role: StringRole,
+    value_role: StringRole,
+    raw_strings: &mut Vec<Vec<u8>>,
+    roles: &mut Vec<StringRole>,
+) {
+    for attribute in attributes {
+        raw_strings.push(attribute.key.clone().into_bytes());
+        roles.push(key_role);
+
+        if let Some(Value::StringValue(s)) = attribute.value.as_ref().and_then(|v| v.value.as_ref())
+        {
+            raw_strings.push(s.clone().into_bytes());
+            roles.push(value_role);
+        }
+    }
+}
+
+/// Decodes a serialized `ExportLogsServiceRequest` and extracts every
+/// string-bearing field -- resource/scope/log attribute keys and string
+/// values, `severity_text`, `event_name`, scope name/version, and schema URLs
+/// -- in a deterministic traversal order (`resource_logs -> scope_logs ->
+/// log_records`), then feeds those byte slices through the existing
+/// dictionary + simdutf8 validation path.
+///
+/// Non-string attribute values (bools, ints, doubles, arrays, kvlists, bytes)
+/// are not string-bearing and are skipped.
+pub fn decode_export_logs_service_request(
+    bytes: &[u8],
+) -> Result<DecodedOtlpLogs, OtlpDecodeError> {
+    let request = ExportLogsServiceRequest::decode(bytes)?;
+
+    let mut raw_strings: Vec<Vec<u8>> = Vec::new();
+    let mut roles: Vec<StringRole> = Vec::new();
+
+    for resource_logs in &request.resource_logs {
+        if let Some(resource) = &resource_logs.resource {
+            collect_attributes(
+                &resource.attributes,
+                StringRole::ResourceAttributeKey,
+                StringRole::ResourceAttributeValue,
+                &mut raw_strings,
+                &mut roles,
+            );
+        }
+
+        for scope_logs in &resource_logs.scope_logs {
+            if let Some(scope) = &scope_logs.scope {
+                raw_strings.push(scope.name.clone().into_bytes());
+                roles.push(StringRole::ScopeName);
+                raw_strings.push(scope.version.clone().into_bytes());
+                roles.push(StringRole::ScopeVersion);
+                collect_attributes(
+                    &scope.attributes,
+                    StringRole::ScopeAttributeKey,
+                    StringRole::ScopeAttributeValue,
+                    &mut raw_strings,
+                    &mut roles,
+                );
+            }
+
+            for log_record in &scope_logs.log_records {
+                raw_strings.push(log_record.severity_text.clone().into_bytes());
+                roles.push(StringRole::SeverityText);
+                raw_strings.push(log_record.event_name.clone().into_bytes());
+                roles.push(StringRole::EventName);
+                collect_attributes(
+                    &log_record.attributes,
+                    StringRole::LogAttributeKey,
+                    StringRole::LogAttributeValue,
+                    &mut raw_strings,
+                    &mut roles,
+                );
+            }
+
+            raw_strings.push(scope_logs.schema_url.clone().into_bytes());
+            roles.push(StringRole::SchemaUrl);
+        }
+
+        raw_strings.push(resource_logs.schema_url.clone().into_bytes());
+        roles.push(StringRole::SchemaUrl);
+    }
+
+    let dictionary = process_otlp_strings_dict(raw_strings)?;
+
+    Ok(DecodedOtlpLogs { dictionary, roles })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry_proto::tonic::common::v1::{AnyValue, InstrumentationScope};
+    use opentelemetry_proto::tonic::logs::v1::{LogRecord, ResourceLogs, ScopeLogs};
+    use opentelemetry_proto::tonic::resource::v1::Resource;
+
+    fn string_attribute(key: &str, value: &str) -> KeyValue {
+        KeyValue {
+            key: key.to_string(),
+            value: Some(AnyValue {
+                value: Some(Value::StringValue(value.to_string())),
+            }),
+        }
+    }
+
+    fn bool_attribute(key: &str, value: bool) -> KeyValue {
+        KeyValue {
+            key: key.to_string(),
+            value: Some(AnyValue {
+                value: Some(Value::BoolValue(value)),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_decode_export_logs_service_request() {
+        // Two resources sharing a "deployment.environment" attribute value,
+        // and a non-string attribute that should be skipped entirely.
+        let request = ExportLogsServiceRequest {
+            resource_logs: vec![
+                ResourceLogs {
+                    resource: Some(Resource {
+                        attributes: vec![
+                            string_attribute("service.name", "user-service"),
+                            bool_attribute("service.experimental", true),
+                            string_attribute("deployment.environment", "production"),
+                        ],
+                        ..Default::default()
+                    }),
+                    scope_logs: vec![ScopeLogs {
+                        scope: Some(InstrumentationScope {
+                            name: "github.com/user-service/logger".to_string(),
+                            version: "v0.1.0".to_string(),
+                            ..Default::default()
+                        }),
+                        log_records: vec![LogRecord {
+                            severity_text: "INFO".to_string(),
+                            event_name: "http_request_completed".to_string(),
+                            attributes: vec![string_attribute("http.method", "GET")],
+                            ..Default::default()
+                        }],
+                        schema_url: "https://opentelemetry.io/schemas/1.21.0".to_string(),
+                    }],
+                    schema_url: "https://opentelemetry.io/schemas/1.21.0".to_string(),
+                },
+                ResourceLogs {
+                    resource: Some(Resource {
+                        attributes: vec![
+                            string_attribute("service.name", "payment-service"),
+                            string_attribute("deployment.environment", "production"),
+                        ],
+                        ..Default::default()
+                    }),
+                    scope_logs: vec![ScopeLogs {
+                        scope: Some(InstrumentationScope {
+                            name: "github.com/payment-service/tracer".to_string(),
+                            version: "v1.0.0".to_string(),
+                            ..Default::default()
+                        }),
+                        log_records: vec![LogRecord {
+                            severity_text: "WARN".to_string(),
+                            event_name: "payment_processing_slow".to_string(),
+                            attributes: vec![string_attribute("payment.currency", "USD")],
+                            ..Default::default()
+                        }],
+                        schema_url: "https://opentelemetry.io/schemas/1.21.0".to_string(),
+                    }],
+                    schema_url: "https://opentelemetry.io/schemas/1.21.0".to_string(),
+                },
+            ],
+        };
+
+        let bytes = request.encode_to_vec();
+        let decoded = decode_export_logs_service_request(&bytes)
+            .expect("well-formed request should decode and validate");
+
+        // The non-string "service.experimental" value is skipped, but its key
+        // is still extracted (attribute keys are always strings).
+        assert!(decoded.dictionary.values.contains(&"service.experimental".to_string()));
+        assert!(!decoded.dictionary.values.contains(&"true".to_string()));
+
+        // Values repeated across resources (schema URL, "production",
+        // "deployment.environment") dedup to a single dictionary entry.
+        let schema_url_count = decoded
+            .dictionary
+            .values
+            .iter()
+            .filter(|v| v.as_str() == "https://opentelemetry.io/schemas/1.21.0")
+            .count();
+        assert_eq!(schema_url_count, 1);
+
+        let production_count = decoded
+            .dictionary
+            .values
+            .iter()
+            .filter(|v| v.as_str() == "production")
+            .count();
+        assert_eq!(production_count, 1);
+
+        // `roles` is parallel to `dictionary.keys`: every extracted field gets
+        // exactly one role tag, in the same deterministic traversal order.
+        assert_eq!(decoded.roles.len(), decoded.dictionary.keys.len());
+
+        // First string extracted is the first resource's first attribute key.
+        assert_eq!(decoded.roles[0], StringRole::ResourceAttributeKey);
+        assert_eq!(
+            decoded.dictionary.values[decoded.dictionary.keys[0] as usize],
+            "service.name"
+        );
+
+        // Schema URL appears four times in the key stream (scope and resource
+        // schema URL, for each of the two resources) but all occurrences
+        // point at the same deduped dictionary entry and are tagged SchemaUrl.
+        let schema_url_positions: Vec<usize> = decoded
+            .roles
+            .iter()
+            .enumerate()
+            .filter(|(_, role)| **role == StringRole::SchemaUrl)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(schema_url_positions.len(), 4); // 2 resources x (scope schema_url + resource schema_url)
+        let schema_url_dict_indices: std::collections::HashSet<u32> = schema_url_positions
+            .iter()
+            .map(|&i| decoded.dictionary.keys[i])
+            .collect();
+        assert_eq!(schema_url_dict_indices.len(), 1);
+    }
+}
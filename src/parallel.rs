@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::thread;
+
+use simdutf8::basic::from_utf8;
+
+use crate::{DictionaryArrayResult, OtlpProcessingError, ProcessingMode};
+
+/// Below this many inputs, the overhead of spinning up threads outweighs the
+/// single-threaded dictionary build, so `process_otlp_strings_parallel` falls
+/// back to the sequential path.
+const PARALLEL_THRESHOLD: usize = 10_000;
+
+/// One chunk's local dictionary: `local_values[i]` is the i-th locally-unique
+/// byte string (in order of first appearance within the chunk),
+/// `local_first_input_index[i]` is that entry's absolute position in the
+/// original input, and `local_keys[i]` is the local index for input position
+/// `i` within the chunk.
+///
+/// Building this performs no UTF-8 validation -- a string repeated across
+/// chunks (the common OTLP case: `service.name`, schema URLs, and the like
+/// appear in nearly every record) would otherwise get checked once per chunk
+/// it appears in instead of once globally. Validation is deferred to the
+/// merge step in `process_otlp_strings_parallel_with_mode`, which only
+/// touches an entry the first time it is inserted into the global
+/// dictionary.
+struct ChunkDictionary {
+    local_values: Vec<Vec<u8>>,
+    local_first_input_index: Vec<usize>,
+    local_keys: Vec<usize>,
+}
+
+/// Builds one chunk's local dictionary (no validation -- see
+/// `ChunkDictionary`). `chunk_offset` is this chunk's starting position
+/// within the original input, so `local_first_input_index` can record
+/// absolute positions.
+fn build_chunk_dictionary(chunk: &[Vec<u8>], chunk_offset: usize) -> ChunkDictionary {
+    let mut local_dictionary: HashMap<&[u8], usize> = HashMap::new();
+    let mut local_values: Vec<Vec<u8>> = Vec::new();
+    let mut local_first_input_index: Vec<usize> = Vec::new();
+    let mut local_keys = Vec::with_capacity(chunk.len());
+
+    for (local_input_index, raw) in chunk.iter().enumerate() {
+        if let Some(&index) = local_dictionary.get(raw.as_slice()) {
+            local_keys.push(index);
+        } else {
+            let index = local_values.len();
+            local_dictionary.insert(raw.as_slice(), index);
+            local_values.push(raw.clone());
+            local_first_input_index.push(chunk_offset + local_input_index);
+            local_keys.push(index);
+        }
+    }
+
+    ChunkDictionary {
+        local_values,
+        local_first_input_index,
+        local_keys,
+    }
+}
+
+/// Parallel version of `process_otlp_strings_dict` for large batches: splits
+/// `raw_strings` into contiguous chunks (one per available CPU), builds each
+/// chunk's local dictionary on its own thread, then merges the per-chunk
+/// dictionaries into one global dictionary, validating along the way.
+///
+/// The merge iterates chunks in order, so `values` is assigned by first
+/// global appearance and the result is deterministic regardless of thread
+/// count. Validation happens exactly once per globally-unique string: an
+/// entry is only checked the first time the merge inserts it into the global
+/// dictionary, regardless of how many chunks it appears in.
+///
+/// Falls back to the sequential `process_otlp_strings_dict` below
+/// `PARALLEL_THRESHOLD` inputs, where thread setup would cost more than it
+/// saves.
+///
+/// This is a convenience wrapper around
+/// [`process_otlp_strings_parallel_with_mode`] for callers that want strict
+/// failure on invalid UTF-8.
+pub fn process_otlp_strings_parallel(
+    raw_strings: Vec<Vec<u8>>,
+) -> Result<DictionaryArrayResult, OtlpProcessingError> {
+    process_otlp_strings_parallel_with_mode(raw_strings, ProcessingMode::Strict)
+}
+
+/// Parallel version of `process_otlp_strings_dict_with_mode` for large
+/// batches: splits `raw_strings` into contiguous chunks (one per available
+/// CPU), builds each chunk's local dictionary on its own thread, then merges
+/// the per-chunk dictionaries into one global dictionary, validating along
+/// the way.
+///
+/// The merge iterates chunks in order, so `values` is assigned by first
+/// global appearance and the result is deterministic regardless of thread
+/// count. In `Strict` mode, an entry is validated exactly once globally: the
+/// first time the merge inserts it into the global dictionary, regardless of
+/// how many chunks it appears in. In `Lossy` mode, that same first insertion
+/// is instead converted with `String::from_utf8_lossy`.
+///
+/// Falls back to the sequential `process_otlp_strings_dict_with_mode` below
+/// `PARALLEL_THRESHOLD` inputs, where thread setup would cost more than it
+/// saves.
+pub fn process_otlp_strings_parallel_with_mode(
+    raw_strings: Vec<Vec<u8>>,
+    mode: ProcessingMode,
+) -> Result<DictionaryArrayResult, OtlpProcessingError> {
+    let num_threads = num_cpus::get().max(1);
+
+    if raw_strings.len() < PARALLEL_THRESHOLD || num_threads <= 1 {
+        return crate::process_otlp_strings_dict_with_mode(raw_strings, mode);
+    }
+
+    let chunk_size = (raw_strings.len() + num_threads - 1) / num_threads;
+
+    let chunk_dictionaries: Vec<ChunkDictionary> = thread::scope(|scope| {
+        let handles: Vec<_> = raw_strings
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(chunk_index, chunk)| {
+                let chunk_offset = chunk_index * chunk_size;
+                scope.spawn(move || build_chunk_dictionary(chunk, chunk_offset))
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("chunk dictionary thread panicked"))
+            .collect()
+    });
+
+    // Merge step: walk chunks in original order, assigning each locally-unique
+    // entry a global index on first global appearance and recording a local
+    // -> global remap table, then rewrite each chunk's local keys through it.
+    // Validation happens here too, exactly once per entry's first global
+    // appearance -- never once per chunk.
+    let mut global_dictionary: HashMap<Vec<u8>, usize> = HashMap::new();
+    let mut global_values: Vec<String> = Vec::new();
+    let mut global_keys: Vec<u32> = Vec::with_capacity(raw_strings.len());
+
+    for chunk in chunk_dictionaries {
+        let mut remap: Vec<u32> = Vec::with_capacity(chunk.local_values.len());
+
+        let entries = chunk.local_values.into_iter().zip(chunk.local_first_input_index);
+        for (local_value, first_input_index) in entries {
+            let global_index = match global_dictionary.get(&local_value) {
+                Some(&index) => index,
+                None => {
+                    let index = global_values.len();
+                    let s = match mode {
+                        ProcessingMode::Strict => match from_utf8(&local_value) {
+                            Ok(_) => unsafe { String::from_utf8_unchecked(local_value.clone()) },
+                            Err(source) => {
+                                return Err(OtlpProcessingError::Utf8Error {
+                                    dict_index: index,
+                                    first_input_index,
+                                    source,
+                                })
+                            }
+                        },
+                        ProcessingMode::Lossy => {
+                            String::from_utf8_lossy(&local_value).into_owned()
+                        }
+                    };
+                    global_dictionary.insert(local_value, index);
+                    global_values.push(s);
+                    index
+                }
+            };
+            remap.push(global_index as u32);
+        }
+
+        for local_key in chunk.local_keys {
+            global_keys.push(remap[local_key]);
+        }
+    }
+
+    Ok(DictionaryArrayResult {
+        values: global_values,
+        keys: global_keys,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_otlp_strings_parallel_matches_sequential() {
+        // Exceed PARALLEL_THRESHOLD so the parallel path actually splits into
+        // chunks, with a handful of repeating values so both the per-chunk
+        // and cross-chunk merge dedup logic get exercised.
+        let values = ["service.name", "status", "region", "http.method"];
+        let raw_data: Vec<Vec<u8>> = (0..PARALLEL_THRESHOLD * 2)
+            .map(|i| values[i % values.len()].as_bytes().to_vec())
+            .collect();
+
+        let parallel =
+            process_otlp_strings_parallel(raw_data.clone()).expect("UTF-8 validation failed");
+        let sequential =
+            crate::process_otlp_strings_dict(raw_data).expect("UTF-8 validation failed");
+
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn test_process_otlp_strings_parallel_invalid_utf8() {
+        let mut raw_data: Vec<Vec<u8>> = (0..PARALLEL_THRESHOLD * 2)
+            .map(|i| format!("value-{}", i % 8).into_bytes())
+            .collect();
+        raw_data.push(vec![0xFF, 0xFE]);
+
+        let result = process_otlp_strings_parallel(raw_data);
+        assert!(result.is_err(), "Should fail on invalid UTF-8 data");
+    }
+
+    #[test]
+    fn test_process_otlp_strings_parallel_lossy_mode() {
+        let mut raw_data: Vec<Vec<u8>> = (0..PARALLEL_THRESHOLD * 2)
+            .map(|i| format!("value-{}", i % 8).into_bytes())
+            .collect();
+        raw_data.push(vec![0xFF, 0xFE]);
+
+        let result = process_otlp_strings_parallel_with_mode(raw_data, ProcessingMode::Lossy)
+            .expect("lossy mode should not fail on invalid UTF-8");
+
+        assert!(result.values.iter().any(|s| s.contains('\u{FFFD}')));
+    }
+
+    #[test]
+    fn test_process_otlp_strings_parallel_dedups_value_repeated_across_chunks() {
+        // A value repeated in every chunk (the realistic OTLP case) must end
+        // up as a single global dictionary entry, not one per chunk it
+        // appears in.
+        let raw_data: Vec<Vec<u8>> = (0..PARALLEL_THRESHOLD * 2)
+            .map(|i| {
+                if i % 100 == 0 {
+                    b"service.name".to_vec()
+                } else {
+                    format!("value-{}", i).into_bytes()
+                }
+            })
+            .collect();
+
+        let result = process_otlp_strings_parallel(raw_data).expect("UTF-8 validation failed");
+
+        let service_name_count = result
+            .values
+            .iter()
+            .filter(|v| v.as_str() == "service.name")
+            .count();
+        assert_eq!(service_name_count, 1);
+    }
+}